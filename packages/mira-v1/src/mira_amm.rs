@@ -0,0 +1,245 @@
+use crate::interface::{
+    AddLiquidityScript, AddLiquidityScriptConfigurables, Asset, MiraAmmContract, PoolId,
+    RemoveLiquidityScript, RemoveLiquidityScriptConfigurables, SwapExactInputScript,
+    SwapExactInputScriptConfigurables, SwapExactOutputScript, SwapExactOutputScriptConfigurables,
+    ADD_LIQUIDITY_SCRIPT_BINARY_PATH, REMOVE_LIQUIDITY_SCRIPT_BINARY_PATH,
+    SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH, SWAP_EXACT_OUTPUT_SCRIPT_BINARY_PATH,
+};
+use crate::pool_status;
+use crate::utils::{get_asset_id_in, get_transaction_inputs_outputs};
+use crate::watchable::PendingTransaction;
+use fuels::prelude::{AssetId, Bech32ContractId, Execution, Result, TxPolicies, WalletUnlocked};
+use fuels::types::transaction_builders::VariableOutputPolicy;
+use fuels::types::ContractId;
+use std::str::FromStr;
+
+use crate::constants::DEFAULT_AMM_CONTRACT_ID;
+
+fn sufficient_tx_policies() -> TxPolicies {
+    TxPolicies::default().with_max_fee(1_000_000_000)
+}
+
+/// A `ReadonlyMiraAmm` sibling that submits real transactions with `wallet` instead of only
+/// simulating them.
+pub struct MiraAmm {
+    wallet: WalletUnlocked,
+    amm_contract: MiraAmmContract<WalletUnlocked>,
+    add_liquidity_script: AddLiquidityScript<WalletUnlocked>,
+    remove_liquidity_script: RemoveLiquidityScript<WalletUnlocked>,
+    swap_exact_input_script: SwapExactInputScript<WalletUnlocked>,
+    swap_exact_output_script: SwapExactOutputScript<WalletUnlocked>,
+}
+
+impl MiraAmm {
+    pub fn connect(wallet: WalletUnlocked, contract_id: Option<ContractId>) -> Result<Self> {
+        let amm_contract = MiraAmmContract::new(
+            contract_id.unwrap_or(ContractId::from_str(DEFAULT_AMM_CONTRACT_ID).unwrap()),
+            wallet.clone(),
+        );
+        let add_liquidity_script =
+            AddLiquidityScript::new(wallet.clone(), ADD_LIQUIDITY_SCRIPT_BINARY_PATH)
+                .with_configurables(
+                    AddLiquidityScriptConfigurables::default()
+                        .with_AMM_CONTRACT_ID(amm_contract.contract_id().into())
+                        .unwrap(),
+                );
+        let remove_liquidity_script =
+            RemoveLiquidityScript::new(wallet.clone(), REMOVE_LIQUIDITY_SCRIPT_BINARY_PATH)
+                .with_configurables(
+                    RemoveLiquidityScriptConfigurables::default()
+                        .with_AMM_CONTRACT_ID(amm_contract.contract_id().into())
+                        .unwrap(),
+                );
+        let swap_exact_input_script =
+            SwapExactInputScript::new(wallet.clone(), SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH)
+                .with_configurables(
+                    SwapExactInputScriptConfigurables::default()
+                        .with_AMM_CONTRACT_ID(amm_contract.contract_id().into())
+                        .unwrap(),
+                );
+        let swap_exact_output_script =
+            SwapExactOutputScript::new(wallet.clone(), SWAP_EXACT_OUTPUT_SCRIPT_BINARY_PATH)
+                .with_configurables(
+                    SwapExactOutputScriptConfigurables::default()
+                        .with_AMM_CONTRACT_ID(amm_contract.contract_id().into())
+                        .unwrap(),
+                );
+
+        Ok(Self {
+            wallet,
+            amm_contract,
+            add_liquidity_script,
+            remove_liquidity_script,
+            swap_exact_input_script,
+            swap_exact_output_script,
+        })
+    }
+
+    pub fn id(&self) -> &Bech32ContractId {
+        self.amm_contract.contract_id()
+    }
+
+    /// Status is derived from `pool_metadata` — the contract exposes no dedicated getter.
+    async fn require_tradeable(&self, pool_id: PoolId) -> Result<()> {
+        let metadata = self
+            .amm_contract
+            .methods()
+            .pool_metadata(pool_id)
+            .with_tx_policies(sufficient_tx_policies())
+            .simulate(Execution::StateReadOnly)
+            .await?
+            .value;
+        pool_status::require_tradeable(pool_id, metadata.as_ref())
+    }
+
+    pub async fn add_liquidity(
+        &self,
+        pool_id: PoolId,
+        amount_0_desired: u64,
+        amount_1_desired: u64,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        deadline: u32,
+        tx_policies: Option<TxPolicies>,
+    ) -> Result<PendingTransaction> {
+        let (inputs, outputs) = get_transaction_inputs_outputs(
+            &self.wallet,
+            &vec![(pool_id.0, amount_0_desired), (pool_id.1, amount_1_desired)],
+        )
+        .await;
+        let submitted = self
+            .add_liquidity_script
+            .main(
+                pool_id,
+                amount_0_desired,
+                amount_1_desired,
+                amount_0_min,
+                amount_1_min,
+                self.wallet.address().into(),
+                deadline,
+            )
+            .with_tx_policies(tx_policies.unwrap_or_default())
+            .with_contracts(&[&self.amm_contract])
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .submit()
+            .await?;
+        Ok(PendingTransaction::new(
+            self.wallet.try_provider()?.clone(),
+            submitted.tx_id,
+        ))
+    }
+
+    pub async fn remove_liquidity(
+        &self,
+        pool_id: PoolId,
+        lp_asset: Asset,
+        amount_0_min: u64,
+        amount_1_min: u64,
+        deadline: u32,
+        tx_policies: Option<TxPolicies>,
+    ) -> Result<PendingTransaction> {
+        let (inputs, outputs) = get_transaction_inputs_outputs(
+            &self.wallet,
+            &vec![(lp_asset.id, lp_asset.amount)],
+        )
+        .await;
+        let submitted = self
+            .remove_liquidity_script
+            .main(
+                pool_id,
+                lp_asset.amount,
+                amount_0_min,
+                amount_1_min,
+                self.wallet.address().into(),
+                deadline,
+            )
+            .with_tx_policies(tx_policies.unwrap_or_default())
+            .with_contracts(&[&self.amm_contract])
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .submit()
+            .await?;
+        Ok(PendingTransaction::new(
+            self.wallet.try_provider()?.clone(),
+            submitted.tx_id,
+        ))
+    }
+
+    pub async fn swap_exact_input(
+        &self,
+        amount_in: u64,
+        asset_in: AssetId,
+        amount_out_min: u64,
+        pools: Vec<PoolId>,
+        deadline: u32,
+        tx_policies: Option<TxPolicies>,
+    ) -> Result<PendingTransaction> {
+        for &pool_id in &pools {
+            self.require_tradeable(pool_id).await?;
+        }
+        let (inputs, outputs) =
+            get_transaction_inputs_outputs(&self.wallet, &vec![(asset_in, amount_in)]).await;
+        let submitted = self
+            .swap_exact_input_script
+            .main(
+                amount_in,
+                asset_in,
+                amount_out_min,
+                pools,
+                self.wallet.address().into(),
+                deadline,
+            )
+            .with_tx_policies(tx_policies.unwrap_or_default())
+            .with_contracts(&[&self.amm_contract])
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .submit()
+            .await?;
+        Ok(PendingTransaction::new(
+            self.wallet.try_provider()?.clone(),
+            submitted.tx_id,
+        ))
+    }
+
+    pub async fn swap_exact_output(
+        &self,
+        amount_out: u64,
+        asset_out: AssetId,
+        amount_in_max: u64,
+        pools: Vec<PoolId>,
+        deadline: u32,
+        tx_policies: Option<TxPolicies>,
+    ) -> Result<PendingTransaction> {
+        for &pool_id in &pools {
+            self.require_tradeable(pool_id).await?;
+        }
+        let asset_in = get_asset_id_in(asset_out, &pools);
+        let (inputs, outputs) =
+            get_transaction_inputs_outputs(&self.wallet, &vec![(asset_in, amount_in_max)]).await;
+        let submitted = self
+            .swap_exact_output_script
+            .main(
+                amount_out,
+                asset_out,
+                amount_in_max,
+                pools,
+                self.wallet.address().into(),
+                deadline,
+            )
+            .with_tx_policies(tx_policies.unwrap_or_default())
+            .with_contracts(&[&self.amm_contract])
+            .with_inputs(inputs)
+            .with_outputs(outputs)
+            .with_variable_output_policy(VariableOutputPolicy::Exactly(1))
+            .submit()
+            .await?;
+        Ok(PendingTransaction::new(
+            self.wallet.try_provider()?.clone(),
+            submitted.tx_id,
+        ))
+    }
+}
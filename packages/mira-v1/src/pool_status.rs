@@ -0,0 +1,114 @@
+use crate::interface::{PoolId, PoolMetadata};
+use fuels::prelude::{error, Result};
+
+/// Lifecycle status of a pool, derived from its reserves rather than a dedicated on-chain
+/// getter (the Mira v1 AMM contract exposes no such entry-point). A pool that hasn't received
+/// its first liquidity deposit reports `Initialized` — LP add/remove is still permitted, but
+/// there's nothing to swap against yet; once both reserves are non-zero it's `Active`.
+///
+/// Because this is derived from reserves rather than tracked on-chain state, it cannot
+/// distinguish a pool that was never funded from one that was funded and then fully drained:
+/// both report `Initialized`. There is no `Closed` variant — nothing observable from
+/// `pool_metadata` alone would make that call honestly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolStatus {
+    Initialized,
+    Active,
+}
+
+pub fn pool_status(metadata: &PoolMetadata) -> PoolStatus {
+    if metadata.reserve_0 == 0 || metadata.reserve_1 == 0 {
+        PoolStatus::Initialized
+    } else {
+        PoolStatus::Active
+    }
+}
+
+pub fn can_trade(metadata: Option<&PoolMetadata>) -> bool {
+    metadata.map(pool_status) == Some(PoolStatus::Active)
+}
+
+pub fn can_provide_liquidity(metadata: Option<&PoolMetadata>) -> bool {
+    metadata.is_some()
+}
+
+/// Shared by both `ReadonlyMiraAmm` and `MiraAmm` so the two clients reject untradeable pools
+/// with the same message instead of each hand-rolling the check. Distinguishes a pool that
+/// doesn't exist at all from one that exists but currently has no reserves to trade against,
+/// since conflating the two under one "not open for trading" message would mislead a caller
+/// into thinking a drained pool was never funded.
+pub fn require_tradeable(pool_id: PoolId, metadata: Option<&PoolMetadata>) -> Result<()> {
+    let Some(metadata) = metadata else {
+        return Err(error!(Other, "pool {pool_id:?} does not exist"));
+    };
+    if can_trade(Some(metadata)) {
+        Ok(())
+    } else {
+        Err(error!(
+            Other,
+            "pool {pool_id:?} has no reserves to trade against"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::Asset;
+    use fuels::prelude::AssetId;
+
+    fn asset(byte: u8) -> AssetId {
+        AssetId::new([byte; 32])
+    }
+
+    fn metadata(reserve_0: u64, reserve_1: u64) -> PoolMetadata {
+        PoolMetadata {
+            pool_id: (asset(1), asset(2), false),
+            reserve_0,
+            reserve_1,
+            liquidity: Asset {
+                id: asset(3),
+                amount: 0,
+            },
+            decimals_0: 9,
+            decimals_1: 9,
+        }
+    }
+
+    #[test]
+    fn reports_active_once_both_reserves_are_funded() {
+        assert_eq!(pool_status(&metadata(1_000, 1_000)), PoolStatus::Active);
+    }
+
+    #[test]
+    fn reports_initialized_when_either_reserve_is_zero() {
+        assert_eq!(pool_status(&metadata(0, 1_000)), PoolStatus::Initialized);
+        assert_eq!(pool_status(&metadata(1_000, 0)), PoolStatus::Initialized);
+        assert_eq!(pool_status(&metadata(0, 0)), PoolStatus::Initialized);
+    }
+
+    #[test]
+    fn can_trade_is_false_for_a_nonexistent_or_unfunded_pool() {
+        assert!(!can_trade(None));
+        assert!(!can_trade(Some(&metadata(0, 1_000))));
+        assert!(can_trade(Some(&metadata(1_000, 1_000))));
+    }
+
+    #[test]
+    fn can_provide_liquidity_only_requires_the_pool_to_exist() {
+        assert!(!can_provide_liquidity(None));
+        assert!(can_provide_liquidity(Some(&metadata(0, 0))));
+    }
+
+    #[test]
+    fn require_tradeable_distinguishes_missing_pool_from_empty_pool() {
+        let pool_id = (asset(1), asset(2), false);
+        let missing = require_tradeable(pool_id, None).unwrap_err();
+        assert!(missing.to_string().contains("does not exist"));
+
+        let empty = require_tradeable(pool_id, Some(&metadata(0, 1_000))).unwrap_err();
+        assert!(empty.to_string().contains("no reserves"));
+
+        assert!(require_tradeable(pool_id, Some(&metadata(1_000, 1_000))).is_ok());
+    }
+}
@@ -0,0 +1,388 @@
+use crate::interface::{AmmFees, PoolMetadata};
+use fuels::prelude::AssetId;
+use fuels::types::U256;
+
+const FEE_DENOMINATOR: u64 = 10_000;
+// `PoolMetadata` carries no amplification field — the contract's `pool_metadata` getter doesn't
+// return one, and there's no other read that does — so stable quotes assume every stable pool
+// runs at this fixed `A`. If a deployed pool is configured with a different amplification, these
+// quotes will diverge from what the contract actually executes. Treat this as a hard assumption
+// until the contract exposes per-pool `A`, and validate against a live stable pool before relying
+// on stable-quote output for anything that isn't purely advisory.
+const STABLE_AMPLIFICATION_COEFFICIENT: u128 = 100;
+const STABLE_NEWTON_ITERATIONS: u32 = 255;
+
+fn total_fee_bps(fees: &AmmFees, is_stable: bool) -> u64 {
+    if is_stable {
+        fees.lp_fee_stable + fees.protocol_fee_stable
+    } else {
+        fees.lp_fee_volatile + fees.protocol_fee_volatile
+    }
+}
+
+// Returns (reserve of `asset`, reserve of the other asset in the pool, is_stable), or
+// `None` if `asset` does not belong to this pool.
+fn reserves_for(metadata: &PoolMetadata, asset: AssetId) -> Option<(u64, u64, bool)> {
+    let (asset_0, asset_1, is_stable) = metadata.pool_id;
+    if asset == asset_0 {
+        Some((metadata.reserve_0, metadata.reserve_1, is_stable))
+    } else if asset == asset_1 {
+        Some((metadata.reserve_1, metadata.reserve_0, is_stable))
+    } else {
+        None
+    }
+}
+
+// `amount_in_after_fee * reserve_out` and the denominator's cross term can both exceed
+// `u128::MAX` for 18-decimal-scale reserves, same as the stableswap terms in `compute_d`/
+// `compute_y` below, so this runs the products through U256 and narrows only the final ratio.
+fn volatile_amount_out(reserve_in: u64, reserve_out: u64, amount_in: u64, fee_bps: u64) -> u64 {
+    let amount_in_after_fee = U256::from(amount_in) * U256::from(FEE_DENOMINATOR - fee_bps);
+    let numerator = amount_in_after_fee * U256::from(reserve_out);
+    let denominator = U256::from(reserve_in) * U256::from(FEE_DENOMINATOR) + amount_in_after_fee;
+    (numerator / denominator).as_u64()
+}
+
+fn volatile_amount_in(reserve_in: u64, reserve_out: u64, amount_out: u64, fee_bps: u64) -> Option<u64> {
+    if amount_out >= reserve_out {
+        return None;
+    }
+    let numerator =
+        U256::from(reserve_in) * U256::from(amount_out) * U256::from(FEE_DENOMINATOR);
+    let denominator = U256::from(reserve_out - amount_out) * U256::from(FEE_DENOMINATOR - fee_bps);
+    Some((numerator / denominator + U256::from(1u8)).as_u64())
+}
+
+// Curve-style invariant for two coins: iterates D until it converges to within 1 unit.
+//
+// Intermediate terms like `d * d` can exceed `u128::MAX` once reserves approach the u64 range
+// (D itself never does — it stays on the order of the reserves' sum), so the iteration runs in
+// U256 and only the converged result is brought back down to u128.
+fn compute_d(x0: u128, x1: u128, amp: u128) -> u128 {
+    let n = U256::from(2u8);
+    let one = U256::from(1u8);
+    let x0 = U256::from(x0);
+    let x1 = U256::from(x1);
+    let amp = U256::from(amp);
+    let s = x0 + x1;
+    if s.is_zero() {
+        return 0;
+    }
+    let ann = amp * n * n;
+    let mut d = s;
+    for _ in 0..STABLE_NEWTON_ITERATIONS {
+        let d_p = d * d / (x0 * n) * d / (x1 * n);
+        let d_prev = d;
+        d = (ann * s + d_p * n) * d / ((ann - one) * d + (n + one) * d_p);
+        let diff = if d > d_prev { d - d_prev } else { d_prev - d };
+        if diff <= one {
+            break;
+        }
+    }
+    d.as_u128()
+}
+
+// Solves for the opposite balance `y` such that the pool stays on the invariant once one side
+// has moved to balance `x`. Same U256 rationale as `compute_d` applies to `y * y`.
+fn compute_y(x: u128, d: u128, amp: u128) -> u128 {
+    let n = U256::from(2u8);
+    let one = U256::from(1u8);
+    let x = U256::from(x);
+    let d = U256::from(d);
+    let amp = U256::from(amp);
+    let ann = amp * n * n;
+    let c = d * d / (x * n) * d / (ann * n);
+    let b = x + d / ann;
+    let mut y = d;
+    for _ in 0..STABLE_NEWTON_ITERATIONS {
+        let y_prev = y;
+        y = (y * y + c) / (n * y + b - d);
+        let diff = if y > y_prev { y - y_prev } else { y_prev - y };
+        if diff <= one {
+            break;
+        }
+    }
+    y.as_u128()
+}
+
+fn stable_amount_out(reserve_in: u64, reserve_out: u64, amount_in: u64, fee_bps: u64) -> Option<u64> {
+    let d = compute_d(reserve_in as u128, reserve_out as u128, STABLE_AMPLIFICATION_COEFFICIENT);
+    let x = reserve_in as u128 + amount_in as u128;
+    let y = compute_y(x, d, STABLE_AMPLIFICATION_COEFFICIENT);
+    let dy = (reserve_out as u128).checked_sub(y)?.checked_sub(1)?;
+    let fee = dy * fee_bps as u128 / FEE_DENOMINATOR as u128;
+    Some((dy - fee) as u64)
+}
+
+fn stable_amount_in(reserve_in: u64, reserve_out: u64, amount_out: u64, fee_bps: u64) -> Option<u64> {
+    let gross_amount_out = amount_out as u128 * FEE_DENOMINATOR as u128 / (FEE_DENOMINATOR - fee_bps) as u128 + 1;
+    if gross_amount_out >= reserve_out as u128 {
+        return None;
+    }
+    let d = compute_d(reserve_in as u128, reserve_out as u128, STABLE_AMPLIFICATION_COEFFICIENT);
+    let y = reserve_out as u128 - gross_amount_out;
+    let x = compute_y(y, d, STABLE_AMPLIFICATION_COEFFICIENT);
+    Some((x - reserve_in as u128 + 1) as u64)
+}
+
+/// Computes the output amount for a swap of `amount_in` of `asset_in` against `metadata`'s
+/// reserves, without simulating a transaction. Returns `None` if `asset_in` is not part of the
+/// pool. For a stable pool this assumes `STABLE_AMPLIFICATION_COEFFICIENT` — see the comment on it.
+pub fn quote_exact_input(
+    metadata: &PoolMetadata,
+    fees: &AmmFees,
+    asset_in: AssetId,
+    amount_in: u64,
+) -> Option<u64> {
+    let (reserve_in, reserve_out, is_stable) = reserves_for(metadata, asset_in)?;
+    if amount_in == 0 || reserve_in == 0 || reserve_out == 0 {
+        return Some(0);
+    }
+    let fee_bps = total_fee_bps(fees, is_stable);
+    if is_stable {
+        stable_amount_out(reserve_in, reserve_out, amount_in, fee_bps)
+    } else {
+        Some(volatile_amount_out(reserve_in, reserve_out, amount_in, fee_bps))
+    }
+}
+
+/// Computes the input amount required to receive `amount_out` of `asset_out` from `metadata`'s
+/// reserves, without simulating a transaction. Returns `None` if `asset_out` is not part of the
+/// pool or if `amount_out` exceeds the available reserve. For a stable pool this assumes
+/// `STABLE_AMPLIFICATION_COEFFICIENT` — see the comment on it.
+pub fn quote_exact_output(
+    metadata: &PoolMetadata,
+    fees: &AmmFees,
+    asset_out: AssetId,
+    amount_out: u64,
+) -> Option<u64> {
+    let (reserve_out, reserve_in, is_stable) = reserves_for(metadata, asset_out)?;
+    if amount_out == 0 {
+        return Some(0);
+    }
+    if reserve_in == 0 || reserve_out == 0 {
+        return None;
+    }
+    let fee_bps = total_fee_bps(fees, is_stable);
+    if is_stable {
+        stable_amount_in(reserve_in, reserve_out, amount_out, fee_bps)
+    } else {
+        volatile_amount_in(reserve_in, reserve_out, amount_out, fee_bps)
+    }
+}
+
+/// Spot price of `asset_in` denominated in the other pool asset, derived purely from reserves
+/// (i.e. with no fee or slippage applied).
+pub fn spot_price(metadata: &PoolMetadata, asset_in: AssetId) -> Option<f64> {
+    let (reserve_in, reserve_out, _) = reserves_for(metadata, asset_in)?;
+    if reserve_in == 0 {
+        return None;
+    }
+    Some(reserve_out as f64 / reserve_in as f64)
+}
+
+fn other_asset(metadata: &PoolMetadata, asset: AssetId) -> Option<AssetId> {
+    let (asset_0, asset_1, _) = metadata.pool_id;
+    if asset == asset_0 {
+        Some(asset_1)
+    } else if asset == asset_1 {
+        Some(asset_0)
+    } else {
+        None
+    }
+}
+
+fn price_impact(spot_price: f64, execution_price: f64) -> f64 {
+    if spot_price == 0.0 {
+        0.0
+    } else {
+        1.0 - (execution_price / spot_price)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExactInputQuote {
+    pub amount_out: u64,
+    pub price_impact: f64,
+    pub amount_out_min: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExactOutputQuote {
+    pub amount_in: u64,
+    pub price_impact: f64,
+    pub amount_in_max: u64,
+}
+
+/// Quotes a swap of `amount_in` of `asset_in`, and derives the `amount_out_min` a caller should
+/// pass to the real swap so that a worse price than `slippage_bps` basis points is rejected.
+pub fn quote_exact_input_with_slippage(
+    metadata: &PoolMetadata,
+    fees: &AmmFees,
+    asset_in: AssetId,
+    amount_in: u64,
+    slippage_bps: u64,
+) -> Option<ExactInputQuote> {
+    let amount_out = quote_exact_input(metadata, fees, asset_in, amount_in)?;
+    let spot = spot_price(metadata, asset_in)?;
+    let execution_price = amount_out as f64 / amount_in as f64;
+    let amount_out_min =
+        (amount_out as u128 * (FEE_DENOMINATOR - slippage_bps.min(FEE_DENOMINATOR)) as u128
+            / FEE_DENOMINATOR as u128) as u64;
+    Some(ExactInputQuote {
+        amount_out,
+        price_impact: price_impact(spot, execution_price),
+        amount_out_min,
+    })
+}
+
+/// Quotes the input required for `amount_out` of `asset_out`, and derives the `amount_in_max` a
+/// caller should pass to the real swap so that a worse price than `slippage_bps` basis points is
+/// rejected.
+pub fn quote_exact_output_with_slippage(
+    metadata: &PoolMetadata,
+    fees: &AmmFees,
+    asset_out: AssetId,
+    amount_out: u64,
+    slippage_bps: u64,
+) -> Option<ExactOutputQuote> {
+    let amount_in = quote_exact_output(metadata, fees, asset_out, amount_out)?;
+    let asset_in = other_asset(metadata, asset_out)?;
+    let spot = spot_price(metadata, asset_in)?;
+    let execution_price = amount_out as f64 / amount_in as f64;
+    let amount_in_max = (amount_in as u128 * (FEE_DENOMINATOR + slippage_bps) as u128
+        / FEE_DENOMINATOR as u128) as u64;
+    Some(ExactOutputQuote {
+        amount_in,
+        price_impact: price_impact(spot, execution_price),
+        amount_in_max,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::Asset;
+
+    fn asset(byte: u8) -> AssetId {
+        AssetId::new([byte; 32])
+    }
+
+    fn metadata(reserve_0: u64, reserve_1: u64, is_stable: bool) -> PoolMetadata {
+        PoolMetadata {
+            pool_id: (asset(1), asset(2), is_stable),
+            reserve_0,
+            reserve_1,
+            liquidity: Asset {
+                id: asset(3),
+                amount: 0,
+            },
+            decimals_0: 9,
+            decimals_1: 9,
+        }
+    }
+
+    fn fees(lp_bps: u64, protocol_bps: u64) -> AmmFees {
+        AmmFees {
+            lp_fee_volatile: lp_bps,
+            lp_fee_stable: lp_bps,
+            protocol_fee_volatile: protocol_bps,
+            protocol_fee_stable: protocol_bps,
+        }
+    }
+
+    #[test]
+    fn volatile_quote_matches_constant_product_formula() {
+        let metadata = metadata(1_000_000, 2_000_000, false);
+        let fees = fees(30, 0); // 0.3% lp fee, no protocol fee
+        let amount_out =
+            quote_exact_input(&metadata, &fees, asset(1), 1_000).expect("asset is in pool");
+        // amount_out = reserve_out * amount_in * (1 - fee) / (reserve_in + amount_in * (1 - fee))
+        let amount_in_after_fee = 1_000u128 * 9_970;
+        let expected = (amount_in_after_fee * 2_000_000)
+            / (1_000_000u128 * 10_000 + amount_in_after_fee);
+        assert_eq!(amount_out, expected as u64);
+    }
+
+    #[test]
+    fn volatile_quote_round_trips_with_zero_fee() {
+        let metadata = metadata(1_000_000, 2_000_000, false);
+        let fees = fees(0, 0);
+        let amount_out = quote_exact_input(&metadata, &fees, asset(1), 1_000).unwrap();
+        let amount_in = quote_exact_output(&metadata, &fees, asset(2), amount_out).unwrap();
+        // Ceil-rounded on the way back in, so it can only ever need a hair more than we started.
+        assert!(amount_in >= 1_000 && amount_in <= 1_001);
+    }
+
+    #[test]
+    fn stable_quote_is_near_one_to_one_for_a_balanced_pool() {
+        let metadata = metadata(1_000_000_000, 1_000_000_000, true);
+        let fees = fees(0, 0);
+        let amount_out = quote_exact_input(&metadata, &fees, asset(1), 1_000).unwrap();
+        // A balanced stableswap pool trades very close to 1:1 for a small swap.
+        assert!(amount_out >= 990 && amount_out <= 1_000);
+    }
+
+    #[test]
+    fn volatile_quote_does_not_panic_on_near_u64_max_reserves() {
+        let metadata = metadata(u64::MAX / 2, u64::MAX / 2, false);
+        let fees = fees(30, 0);
+        let amount_out = quote_exact_input(&metadata, &fees, asset(1), u64::MAX / 4).unwrap();
+        assert!(amount_out > 0 && amount_out < u64::MAX / 4);
+        let amount_in = quote_exact_output(&metadata, &fees, asset(2), u64::MAX / 8).unwrap();
+        assert!(amount_in > 0);
+    }
+
+    #[test]
+    fn stable_quote_does_not_panic_on_near_u64_max_reserves() {
+        let metadata = metadata(u64::MAX / 2, u64::MAX / 2, true);
+        let fees = fees(30, 0);
+        let amount_out = quote_exact_input(&metadata, &fees, asset(1), 1_000_000).unwrap();
+        assert!(amount_out > 0 && amount_out <= 1_000_000);
+    }
+
+    #[test]
+    fn quote_exact_input_on_empty_pool_returns_zero() {
+        let metadata = metadata(0, 0, false);
+        let fees = fees(30, 0);
+        assert_eq!(quote_exact_input(&metadata, &fees, asset(1), 1_000), Some(0));
+    }
+
+    #[test]
+    fn quote_exact_output_on_empty_pool_returns_none_instead_of_panicking() {
+        let metadata = metadata(0, 1_000_000, true);
+        let fees = fees(30, 0);
+        assert_eq!(quote_exact_output(&metadata, &fees, asset(2), 1_000), None);
+
+        let drained = self::metadata(1_000_000, 0, false);
+        assert_eq!(quote_exact_output(&drained, &fees, asset(2), 1_000), None);
+    }
+
+    #[test]
+    fn quote_exact_input_returns_none_for_asset_outside_pool() {
+        let metadata = metadata(1_000_000, 2_000_000, false);
+        let fees = fees(30, 0);
+        assert_eq!(quote_exact_input(&metadata, &fees, asset(9), 1_000), None);
+    }
+
+    #[test]
+    fn price_impact_is_positive_for_a_nontrivial_swap_and_worsens_with_size() {
+        let metadata = metadata(1_000_000, 1_000_000, false);
+        let fees = fees(0, 0);
+        let small = quote_exact_input_with_slippage(&metadata, &fees, asset(1), 1_000, 50).unwrap();
+        let large =
+            quote_exact_input_with_slippage(&metadata, &fees, asset(1), 500_000, 50).unwrap();
+        assert!(small.price_impact >= 0.0);
+        assert!(large.price_impact > small.price_impact);
+        assert!(small.amount_out_min < small.amount_out);
+    }
+
+    #[test]
+    fn exact_output_slippage_guard_is_at_least_the_raw_quote() {
+        let metadata = metadata(1_000_000, 1_000_000, false);
+        let fees = fees(0, 0);
+        let quote =
+            quote_exact_output_with_slippage(&metadata, &fees, asset(2), 10_000, 50).unwrap();
+        assert!(quote.amount_in_max >= quote.amount_in);
+    }
+}
@@ -7,13 +7,17 @@ use crate::interface::{
     ADD_LIQUIDITY_SCRIPT_BINARY_PATH, REMOVE_LIQUIDITY_SCRIPT_BINARY_PATH,
     SWAP_EXACT_INPUT_SCRIPT_BINARY_PATH, SWAP_EXACT_OUTPUT_SCRIPT_BINARY_PATH,
 };
+use crate::pool_status::{self, PoolStatus};
+use crate::quote;
+use crate::router::{self, Route};
 use crate::utils::{get_asset_id_in, get_lp_asset_id, get_transaction_inputs_outputs};
 use fuels::crypto::SecretKey;
 use fuels::prelude::{
-    AssetId, Bech32ContractId, Execution, Provider, Result, TxPolicies, WalletUnlocked,
+    error, AssetId, Bech32ContractId, Execution, Provider, Result, TxPolicies, WalletUnlocked,
 };
 use fuels::types::transaction_builders::VariableOutputPolicy;
 use fuels::types::{ContractId, Identity};
+use futures::try_join;
 use std::str::FromStr;
 
 pub struct ReadonlyMiraAmm {
@@ -26,10 +30,39 @@ pub struct ReadonlyMiraAmm {
     swap_exact_output_script: SwapExactOutputScript<WalletUnlocked>,
 }
 
+pub struct PoolSnapshot {
+    pub pool_metadata: Option<PoolMetadata>,
+    pub pool_status: Option<PoolStatus>,
+    pub lp_asset_info: Option<LpAssetInfo>,
+    pub fees: AmmFees,
+    pub hook: Option<ContractId>,
+}
+
 fn sufficient_tx_policies() -> TxPolicies {
     TxPolicies::default().with_max_fee(1_000_000_000)
 }
 
+// The four `name`/`symbol`/`decimals`/`total_supply` reads only ever come back `None` together
+// (an asset the contract doesn't recognize), so any single `None` is treated as "no LP asset".
+fn combine_lp_asset_info(
+    asset_id: AssetId,
+    name: Option<String>,
+    symbol: Option<String>,
+    decimals: Option<u8>,
+    total_supply: Option<u64>,
+) -> Option<LpAssetInfo> {
+    match (name, symbol, decimals, total_supply) {
+        (Some(name), Some(symbol), Some(decimals), Some(total_supply)) => Some(LpAssetInfo {
+            asset_id,
+            name,
+            symbol,
+            decimals,
+            total_supply,
+        }),
+        _ => None,
+    }
+}
+
 impl ReadonlyMiraAmm {
     pub fn connect(provider: &Provider, contract_id: Option<ContractId>) -> Result<Self> {
         let readonly_secret_key = SecretKey::from_str(READONLY_PRIVATE_KEY)?;
@@ -98,6 +131,26 @@ impl ReadonlyMiraAmm {
             .value)
     }
 
+    /// Derived from `pool_metadata`'s reserves — no dedicated on-chain getter exists.
+    /// Returns `None` if the pool doesn't exist, matching `pool_metadata`'s convention.
+    pub async fn pool_status(&self, pool_id: PoolId) -> Result<Option<PoolStatus>> {
+        Ok(self
+            .pool_metadata(pool_id)
+            .await?
+            .as_ref()
+            .map(pool_status::pool_status))
+    }
+
+    pub async fn can_trade(&self, pool_id: PoolId) -> Result<bool> {
+        let metadata = self.pool_metadata(pool_id).await?;
+        Ok(pool_status::can_trade(metadata.as_ref()))
+    }
+
+    pub async fn can_provide_liquidity(&self, pool_id: PoolId) -> Result<bool> {
+        let metadata = self.pool_metadata(pool_id).await?;
+        Ok(pool_status::can_provide_liquidity(metadata.as_ref()))
+    }
+
     pub async fn fees(&self) -> Result<AmmFees> {
         let (lp_fee_volatile, lp_fee_stable, protocol_fee_volatile, protocol_fee_stable) = self
             .amm_contract
@@ -138,51 +191,62 @@ impl ReadonlyMiraAmm {
     }
 
     pub async fn lp_asset_info(&self, asset_id: AssetId) -> Result<Option<LpAssetInfo>> {
-        let name = self
-            .amm_contract
-            .methods()
-            .name(asset_id)
-            .with_tx_policies(sufficient_tx_policies())
-            .simulate(Execution::StateReadOnly)
-            .await?
-            .value;
-        let symbol = self
-            .amm_contract
-            .methods()
-            .symbol(asset_id)
-            .with_tx_policies(sufficient_tx_policies())
-            .simulate(Execution::StateReadOnly)
-            .await?
-            .value;
-        let decimals = self
-            .amm_contract
-            .methods()
-            .decimals(asset_id)
-            .with_tx_policies(sufficient_tx_policies())
-            .simulate(Execution::StateReadOnly)
-            .await?
-            .value;
-        let total_supply = self
-            .amm_contract
-            .methods()
-            .total_supply(asset_id)
-            .with_tx_policies(sufficient_tx_policies())
-            .simulate(Execution::StateReadOnly)
-            .await?
-            .value;
+        let (name, symbol, decimals, total_supply) = try_join!(
+            self.amm_contract
+                .methods()
+                .name(asset_id)
+                .with_tx_policies(sufficient_tx_policies())
+                .simulate(Execution::StateReadOnly),
+            self.amm_contract
+                .methods()
+                .symbol(asset_id)
+                .with_tx_policies(sufficient_tx_policies())
+                .simulate(Execution::StateReadOnly),
+            self.amm_contract
+                .methods()
+                .decimals(asset_id)
+                .with_tx_policies(sufficient_tx_policies())
+                .simulate(Execution::StateReadOnly),
+            self.amm_contract
+                .methods()
+                .total_supply(asset_id)
+                .with_tx_policies(sufficient_tx_policies())
+                .simulate(Execution::StateReadOnly),
+        )?;
+        Ok(combine_lp_asset_info(
+            asset_id,
+            name.value,
+            symbol.value,
+            decimals.value,
+            total_supply.value,
+        ))
+    }
 
-        match (name, symbol, decimals, total_supply) {
-            (Some(name), Some(symbol), Some(decimals), Some(total_supply)) => {
-                Ok(Some(LpAssetInfo {
-                    asset_id,
-                    name,
-                    symbol,
-                    decimals,
-                    total_supply,
-                }))
-            }
-            _ => Ok(None),
-        }
+    /// Fetches a pool's metadata, LP asset info, fees and hook in a single round-trip by
+    /// firing all seven underlying simulations (`lp_asset_info` accounts for four of them)
+    /// concurrently; `pool_status` is then derived from the fetched metadata rather than
+    /// costing another simulation.
+    pub async fn pool_snapshot(&self, pool_id: PoolId) -> Result<PoolSnapshot> {
+        let lp_asset_id = get_lp_asset_id(self.id().into(), &pool_id);
+        let (pool_metadata, lp_asset_info, fees, hook) = try_join!(
+            self.pool_metadata(pool_id),
+            self.lp_asset_info(lp_asset_id),
+            self.fees(),
+            self.hook(),
+        )?;
+        let pool_status = pool_metadata.as_ref().map(pool_status::pool_status);
+        Ok(PoolSnapshot {
+            pool_metadata,
+            pool_status,
+            lp_asset_info,
+            fees,
+            hook,
+        })
+    }
+
+    async fn require_tradeable(&self, pool_id: PoolId) -> Result<()> {
+        let metadata = self.pool_metadata(pool_id).await?;
+        pool_status::require_tradeable(pool_id, metadata.as_ref())
     }
 
     pub async fn owner(&self) -> Result<Option<Identity>> {
@@ -276,6 +340,118 @@ impl ReadonlyMiraAmm {
         Ok((asset_0, asset_1))
     }
 
+    pub async fn quote_exact_input(
+        &self,
+        pool_id: PoolId,
+        asset_in: AssetId,
+        amount_in: u64,
+    ) -> Result<u64> {
+        let metadata = self
+            .pool_metadata(pool_id)
+            .await?
+            .ok_or_else(|| error!(Other, "pool {pool_id:?} does not exist"))?;
+        let fees = self.fees().await?;
+        quote::quote_exact_input(&metadata, &fees, asset_in, amount_in)
+            .ok_or_else(|| error!(Other, "asset {asset_in} is not part of pool {pool_id:?}"))
+    }
+
+    pub async fn quote_exact_output(
+        &self,
+        pool_id: PoolId,
+        asset_out: AssetId,
+        amount_out: u64,
+    ) -> Result<u64> {
+        let metadata = self
+            .pool_metadata(pool_id)
+            .await?
+            .ok_or_else(|| error!(Other, "pool {pool_id:?} does not exist"))?;
+        let fees = self.fees().await?;
+        quote::quote_exact_output(&metadata, &fees, asset_out, amount_out).ok_or_else(|| {
+            error!(
+                Other,
+                "asset {asset_out} is not part of pool {pool_id:?}, or amount_out exceeds reserves"
+            )
+        })
+    }
+
+    /// Quotes a swap of `amount_in` of `asset_in`, and returns the expected output, the price
+    /// impact versus the pool's current spot price, and the `amount_out_min` to pass to
+    /// `preview_swap_exact_input` so that a worse price than `slippage_bps` basis points is
+    /// rejected.
+    pub async fn quote_exact_input_with_slippage(
+        &self,
+        pool_id: PoolId,
+        asset_in: AssetId,
+        amount_in: u64,
+        slippage_bps: u64,
+    ) -> Result<quote::ExactInputQuote> {
+        let metadata = self
+            .pool_metadata(pool_id)
+            .await?
+            .ok_or_else(|| error!(Other, "pool {pool_id:?} does not exist"))?;
+        let fees = self.fees().await?;
+        quote::quote_exact_input_with_slippage(&metadata, &fees, asset_in, amount_in, slippage_bps)
+            .ok_or_else(|| {
+                error!(
+                    Other,
+                    "asset {asset_in} is not part of pool {pool_id:?}, or its reserve is empty"
+                )
+            })
+    }
+
+    /// Quotes the input required for `amount_out` of `asset_out`, and returns the expected
+    /// input, the price impact versus the pool's current spot price, and the `amount_in_max`
+    /// to pass to `swap_exact_output` so that a worse price than `slippage_bps` basis points
+    /// is rejected.
+    pub async fn quote_exact_output_with_slippage(
+        &self,
+        pool_id: PoolId,
+        asset_out: AssetId,
+        amount_out: u64,
+        slippage_bps: u64,
+    ) -> Result<quote::ExactOutputQuote> {
+        let metadata = self
+            .pool_metadata(pool_id)
+            .await?
+            .ok_or_else(|| error!(Other, "pool {pool_id:?} does not exist"))?;
+        let fees = self.fees().await?;
+        quote::quote_exact_output_with_slippage(
+            &metadata,
+            &fees,
+            asset_out,
+            amount_out,
+            slippage_bps,
+        )
+        .ok_or_else(|| {
+            error!(
+                Other,
+                "asset {asset_out} is not part of pool {pool_id:?}, or amount_out exceeds reserves"
+            )
+        })
+    }
+
+    /// Searches `candidate_pools` for the `PoolId` path that maximizes the output of
+    /// `asset_out` for a swap of `amount_in` of `asset_in`. Feed the resulting
+    /// `Route::pools` straight into `preview_swap_exact_input`/`swap_exact_output`.
+    pub async fn best_route(
+        &self,
+        candidate_pools: Vec<PoolId>,
+        asset_in: AssetId,
+        asset_out: AssetId,
+        amount_in: u64,
+    ) -> Result<Option<Route>> {
+        let mut pools = Vec::with_capacity(candidate_pools.len());
+        for pool_id in candidate_pools {
+            if let Some(metadata) = self.pool_metadata(pool_id).await? {
+                if pool_status::can_trade(Some(&metadata)) {
+                    pools.push(metadata);
+                }
+            }
+        }
+        let fees = self.fees().await?;
+        Ok(router::best_route(&pools, &fees, asset_in, asset_out, amount_in))
+    }
+
     pub async fn preview_swap_exact_input(
         &self,
         amount_in: u64,
@@ -285,6 +461,9 @@ impl ReadonlyMiraAmm {
         deadline: u32,
         tx_policies: Option<TxPolicies>,
     ) -> Result<Vec<(u64, AssetId)>> {
+        for &pool_id in &pools {
+            self.require_tradeable(pool_id).await?;
+        }
         let (inputs, outputs) =
             get_transaction_inputs_outputs(&self.simulation_account, &vec![(asset_in, amount_in)])
                 .await;
@@ -319,6 +498,9 @@ impl ReadonlyMiraAmm {
         deadline: u32,
         tx_policies: Option<TxPolicies>,
     ) -> Result<Vec<(u64, AssetId)>> {
+        for &pool_id in &pools {
+            self.require_tradeable(pool_id).await?;
+        }
         let asset_in = get_asset_id_in(asset_out, &pools);
         let (inputs, outputs) = get_transaction_inputs_outputs(
             &self.simulation_account,
@@ -347,3 +529,43 @@ impl ReadonlyMiraAmm {
         Ok(assets)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn asset_id(byte: u8) -> AssetId {
+        AssetId::new([byte; 32])
+    }
+
+    #[test]
+    fn combines_lp_asset_info_when_every_field_is_present() {
+        let info = combine_lp_asset_info(
+            asset_id(1),
+            Some("Mira LP".to_string()),
+            Some("MIRA-LP".to_string()),
+            Some(9),
+            Some(1_000_000),
+        )
+        .unwrap();
+        assert_eq!(info.asset_id, asset_id(1));
+        assert_eq!(info.name, "Mira LP");
+        assert_eq!(info.symbol, "MIRA-LP");
+        assert_eq!(info.decimals, 9);
+        assert_eq!(info.total_supply, 1_000_000);
+    }
+
+    #[test]
+    fn returns_none_if_any_field_is_missing() {
+        assert!(combine_lp_asset_info(asset_id(1), None, Some("X".to_string()), Some(9), Some(1))
+            .is_none());
+        assert!(combine_lp_asset_info(
+            asset_id(1),
+            Some("X".to_string()),
+            Some("X".to_string()),
+            Some(9),
+            None
+        )
+        .is_none());
+    }
+}
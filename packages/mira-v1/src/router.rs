@@ -0,0 +1,212 @@
+use crate::interface::{AmmFees, PoolId, PoolMetadata};
+use crate::quote;
+use fuels::prelude::AssetId;
+use std::collections::{HashMap, HashSet};
+
+const MAX_HOPS: usize = 4;
+
+#[derive(Debug, Clone)]
+pub struct Route {
+    pub pools: Vec<PoolId>,
+    pub amount_out: u64,
+    pub price_impact: f64,
+}
+
+struct Search<'a> {
+    adjacency: HashMap<AssetId, Vec<&'a PoolMetadata>>,
+    fees: &'a AmmFees,
+    asset_out: AssetId,
+    amount_in: u64,
+    best_route: Option<Route>,
+}
+
+/// Finds the `PoolId` path through `pools` that maximizes the output of `asset_out` for a swap
+/// of `amount_in` of `asset_in`, searching up to `MAX_HOPS` pools deep. Returns `None` if
+/// `asset_out` is unreachable from `asset_in` within that bound.
+pub fn best_route(
+    pools: &[PoolMetadata],
+    fees: &AmmFees,
+    asset_in: AssetId,
+    asset_out: AssetId,
+    amount_in: u64,
+) -> Option<Route> {
+    let mut search = Search {
+        adjacency: build_adjacency(pools),
+        fees,
+        asset_out,
+        amount_in,
+        best_route: None,
+    };
+
+    let mut path = Vec::new();
+    let mut visited_pools = HashSet::new();
+    search.visit(asset_in, amount_in, 1.0, &mut path, &mut visited_pools);
+    search.best_route
+}
+
+fn build_adjacency(pools: &[PoolMetadata]) -> HashMap<AssetId, Vec<&PoolMetadata>> {
+    let mut adjacency: HashMap<AssetId, Vec<&PoolMetadata>> = HashMap::new();
+    for pool in pools {
+        let (asset_0, asset_1, _) = pool.pool_id;
+        adjacency.entry(asset_0).or_default().push(pool);
+        adjacency.entry(asset_1).or_default().push(pool);
+    }
+    adjacency
+}
+
+impl<'a> Search<'a> {
+    fn visit(
+        &mut self,
+        current_asset: AssetId,
+        amount_so_far: u64,
+        spot_price_so_far: f64,
+        path: &mut Vec<PoolId>,
+        visited_pools: &mut HashSet<PoolId>,
+    ) {
+        if current_asset == self.asset_out && !path.is_empty() {
+            let is_better = match &self.best_route {
+                Some(route) => amount_so_far > route.amount_out,
+                None => true,
+            };
+            if is_better {
+                let execution_price = amount_so_far as f64 / self.amount_in as f64;
+                let price_impact = if spot_price_so_far == 0.0 {
+                    0.0
+                } else {
+                    1.0 - (execution_price / spot_price_so_far)
+                };
+                self.best_route = Some(Route {
+                    pools: path.clone(),
+                    amount_out: amount_so_far,
+                    price_impact,
+                });
+            }
+        }
+
+        if path.len() >= MAX_HOPS {
+            return;
+        }
+
+        // No dominance pruning here: which pools a path has already spent (`visited_pools`)
+        // affects which routes remain available to it downstream, so a path with a smaller
+        // `amount_so_far` can still beat a larger one once a later hop is forced to route around
+        // a pool the larger path has consumed. Pruning on `amount_so_far` alone (keyed by asset,
+        // or even by `(asset, hops_used)`) discards those paths and can miss the true optimum.
+        // `MAX_HOPS` keeps this exhaustive search bounded.
+        let Some(edges) = self.adjacency.get(&current_asset) else {
+            return;
+        };
+        for pool in edges.clone() {
+            if visited_pools.contains(&pool.pool_id) {
+                continue;
+            }
+            let Some(amount_out) =
+                quote::quote_exact_input(pool, self.fees, current_asset, amount_so_far)
+            else {
+                continue;
+            };
+            let Some(hop_spot_price) = quote::spot_price(pool, current_asset) else {
+                continue;
+            };
+            if amount_out == 0 {
+                continue;
+            }
+            let (asset_0, asset_1, _) = pool.pool_id;
+            let next_asset = if current_asset == asset_0 { asset_1 } else { asset_0 };
+
+            path.push(pool.pool_id);
+            visited_pools.insert(pool.pool_id);
+            self.visit(
+                next_asset,
+                amount_out,
+                spot_price_so_far * hop_spot_price,
+                path,
+                visited_pools,
+            );
+            visited_pools.remove(&pool.pool_id);
+            path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::Asset;
+
+    fn asset(byte: u8) -> AssetId {
+        AssetId::new([byte; 32])
+    }
+
+    fn pool(asset_0: AssetId, asset_1: AssetId, reserve_0: u64, reserve_1: u64) -> PoolMetadata {
+        PoolMetadata {
+            pool_id: (asset_0, asset_1, false),
+            reserve_0,
+            reserve_1,
+            liquidity: Asset {
+                id: asset(255),
+                amount: 0,
+            },
+            decimals_0: 9,
+            decimals_1: 9,
+        }
+    }
+
+    fn no_fees() -> AmmFees {
+        AmmFees {
+            lp_fee_volatile: 0,
+            lp_fee_stable: 0,
+            protocol_fee_volatile: 0,
+            protocol_fee_stable: 0,
+        }
+    }
+
+    #[test]
+    fn finds_the_only_path_through_a_single_pool() {
+        let (a, b) = (asset(1), asset(2));
+        let pools = vec![pool(a, b, 1_000_000, 1_000_000)];
+        let route = best_route(&pools, &no_fees(), a, b, 1_000).unwrap();
+        assert_eq!(route.pools, vec![(a, b, false)]);
+        assert!(route.amount_out > 0 && route.amount_out < 1_000);
+    }
+
+    #[test]
+    fn prefers_the_two_hop_route_when_it_nets_more_output() {
+        let (a, b, c) = (asset(1), asset(2), asset(3));
+        // Direct a->c pool is shallow (expensive), but a->b->c is deep and cheap.
+        let pools = vec![
+            pool(a, c, 10_000, 10_000),
+            pool(a, b, 1_000_000, 1_000_000),
+            pool(b, c, 1_000_000, 1_000_000),
+        ];
+        let route = best_route(&pools, &no_fees(), a, c, 1_000).unwrap();
+        assert_eq!(route.pools, vec![(a, b, false), (b, c, false)]);
+    }
+
+    #[test]
+    fn does_not_exceed_the_configured_hop_bound() {
+        let assets: Vec<AssetId> = (1..=6).map(asset).collect();
+        let pools: Vec<PoolMetadata> = assets
+            .windows(2)
+            .map(|pair| pool(pair[0], pair[1], 1_000_000, 1_000_000))
+            .collect();
+        // The chain is 5 hops long; MAX_HOPS caps the search at 4, so it should be unreachable.
+        let route = best_route(&pools, &no_fees(), assets[0], assets[5], 1_000);
+        assert!(route.is_none());
+    }
+
+    #[test]
+    fn returns_none_when_target_asset_is_unreachable() {
+        let (a, b, c) = (asset(1), asset(2), asset(3));
+        let pools = vec![pool(a, b, 1_000_000, 1_000_000)];
+        assert!(best_route(&pools, &no_fees(), a, c, 1_000).is_none());
+    }
+
+    #[test]
+    fn price_impact_is_nonnegative_for_a_small_trade_on_a_balanced_pool() {
+        let (a, b) = (asset(1), asset(2));
+        let pools = vec![pool(a, b, 1_000_000, 1_000_000)];
+        let route = best_route(&pools, &no_fees(), a, b, 1_000).unwrap();
+        assert!(route.price_impact >= -1e-9);
+    }
+}
@@ -0,0 +1,100 @@
+use fuels::prelude::{error, Provider, Result};
+use fuels::types::tx_status::TxStatus;
+use fuels::types::{Bytes32, Receipt};
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// A submitted transaction that hasn't yet reached the caller's desired finality depth.
+pub struct PendingTransaction {
+    provider: Provider,
+    tx_id: Bytes32,
+}
+
+impl PendingTransaction {
+    pub fn new(provider: Provider, tx_id: Bytes32) -> Self {
+        Self { provider, tx_id }
+    }
+
+    pub fn tx_id(&self) -> Bytes32 {
+        self.tx_id
+    }
+
+    /// Polls the provider for this transaction's status until it succeeds and has
+    /// `confirmations` blocks of finality behind it, then returns the receipts it produced.
+    /// Resolves with an error as soon as the transaction fails or is squeezed out.
+    pub async fn await_confirmations(&self, confirmations: u32) -> Result<Vec<Receipt>> {
+        let (block_height, receipts) = loop {
+            match self.provider.tx_status(&self.tx_id).await? {
+                TxStatus::Success {
+                    block_height,
+                    receipts,
+                    ..
+                } => break (block_height, receipts),
+                TxStatus::Failure { reason, .. } => {
+                    return Err(error!(
+                        Other,
+                        "transaction {:?} failed: {reason}", self.tx_id
+                    ))
+                }
+                TxStatus::SqueezedOut { reason } => {
+                    return Err(error!(
+                        Other,
+                        "transaction {:?} was squeezed out: {reason}", self.tx_id
+                    ))
+                }
+                TxStatus::PreconfirmationFailure { reason, .. } => {
+                    return Err(error!(
+                        Other,
+                        "transaction {:?} failed (preconfirmation): {reason}", self.tx_id
+                    ))
+                }
+                // Preconfirmed but not yet included in a block; the confirmation count can
+                // only start once the full `Success` status lands, so keep polling.
+                TxStatus::Submitted { .. } | TxStatus::PreconfirmationSuccess { .. } => {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+                // `TxStatus` is `#[non_exhaustive]`: treat any variant we don't know about yet
+                // the same as `Submitted` rather than failing to compile against it.
+                _ => {
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            }
+        };
+
+        while self.confirmations_since(block_height).await? < confirmations {
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+
+        Ok(receipts)
+    }
+
+    async fn confirmations_since(&self, block_height: u32) -> Result<u32> {
+        let current_height = self.provider.chain_info().await?.latest_block.header.height;
+        Ok(confirmations_for(current_height, block_height))
+    }
+}
+
+fn confirmations_for(current_height: u32, block_height: u32) -> u32 {
+    current_height.saturating_sub(block_height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_blocks_since_inclusion() {
+        assert_eq!(confirmations_for(105, 100), 5);
+    }
+
+    #[test]
+    fn reports_zero_confirmations_for_the_inclusion_block_itself() {
+        assert_eq!(confirmations_for(100, 100), 0);
+    }
+
+    #[test]
+    fn does_not_underflow_if_the_chain_tip_is_behind_the_inclusion_height() {
+        assert_eq!(confirmations_for(100, 105), 0);
+    }
+}